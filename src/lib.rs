@@ -1,18 +1,135 @@
 //! Provides a generic `ClampedValue` struct that stores a value and ensures that it is
 //! always within the specified minimum and maximum values.
+//!
+//! This crate is `no_std` compatible. The `std` feature (on by default) pulls in
+//! `std::error::Error` support for [`ClampedError`]; disable default features to build
+//! without `std`.
+
+#![no_std]
+
+#[cfg(feature = "std")]
+extern crate std;
+
+use core::ops::{AddAssign, DivAssign, MulAssign, Sub, SubAssign};
+use num_traits::{Bounded, CheckedDiv, NumCast, SaturatingAdd, SaturatingMul, SaturatingSub, Zero};
+
+#[cfg(feature = "std")]
+use num_traits::float::Float as ClampedFloat;
+#[cfg(not(feature = "std"))]
+use num_traits::float::FloatCore as ClampedFloat;
+
+/// The error returned by the fallible `try_*` constructors and mutators on [`ClampedValue`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ClampedError {
+    /// The minimum was larger than the maximum.
+    MinGreaterThanMax,
+    /// The value was not within the minimum and maximum.
+    ValueOutOfBounds,
+    /// The new minimum was larger than the current value.
+    MinAboveValue,
+    /// The new maximum was smaller than the current value.
+    MaxBelowValue,
+}
+
+impl core::fmt::Display for ClampedError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::MinGreaterThanMax => {
+                write!(f, "the minimum is larger than the maximum")
+            }
+            Self::ValueOutOfBounds => {
+                write!(f, "the value is not within the minimum and maximum")
+            }
+            Self::MinAboveValue => {
+                write!(f, "the new minimum is larger than the current value")
+            }
+            Self::MaxBelowValue => {
+                write!(f, "the new maximum is smaller than the current value")
+            }
+        }
+    }
+}
 
-use num_traits::{SaturatingAdd, SaturatingMul, SaturatingSub};
-use std::ops::{AddAssign, Div, DivAssign, MulAssign, Sub, SubAssign};
+#[cfg(feature = "std")]
+impl std::error::Error for ClampedError {}
 
 /// A value that is clamped between a minimum and maximum value.
 #[derive(Debug)]
-pub struct ClampedValue<T: PartialOrd + Clone> {
+pub struct ClampedValue<T: ClampPolicy> {
     value: T,
     min: T,
     max: T,
 }
 
-impl<T: PartialOrd + Clone> ClampedValue<T> {
+/// Defines how an out-of-range (or otherwise unclampable) value is resolved to one within
+/// `[min, max]`. `PartialOrd` is enough to clamp most types, but floating-point NaN compares as
+/// neither less than, greater than, nor equal to anything and would otherwise slip through
+/// every `PartialOrd`-based bounds check undetected, so `f32`/`f64` override the default to map
+/// it to the minimum instead.
+///
+/// Implemented for the primitive numeric types `ClampedValue` is typically used with; custom
+/// types can opt in with `impl ClampPolicy for MyType {}` to use the default, purely
+/// `PartialOrd`-based behaviour.
+pub trait ClampPolicy: PartialOrd + Clone {
+    /// Returns `true` if `self` falls outside `[min, max]`, including values (such as NaN)
+    /// that every `PartialOrd` comparison against `min`/`max` reports as false.
+    fn is_out_of_bounds(&self, min: &Self, max: &Self) -> bool {
+        *self < *min || *self > *max
+    }
+
+    /// Resolves `value` to a value within `[min, max]`.
+    fn resolve(value: Self, min: &Self, max: &Self) -> Self {
+        if value < *min {
+            min.clone()
+        } else if value > *max {
+            max.clone()
+        } else {
+            value
+        }
+    }
+}
+
+macro_rules! impl_clamp_policy {
+    ($($t:ty),* $(,)?) => {
+        $(impl ClampPolicy for $t {})*
+    };
+}
+
+impl_clamp_policy!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+impl ClampPolicy for f32 {
+    fn is_out_of_bounds(&self, min: &Self, max: &Self) -> bool {
+        self.is_nan() || *self < *min || *self > *max
+    }
+
+    fn resolve(value: Self, min: &Self, max: &Self) -> Self {
+        if value.is_nan() || value < *min {
+            *min
+        } else if value > *max {
+            *max
+        } else {
+            value
+        }
+    }
+}
+
+impl ClampPolicy for f64 {
+    fn is_out_of_bounds(&self, min: &Self, max: &Self) -> bool {
+        self.is_nan() || *self < *min || *self > *max
+    }
+
+    fn resolve(value: Self, min: &Self, max: &Self) -> Self {
+        if value.is_nan() || value < *min {
+            *min
+        } else if value > *max {
+            *max
+        } else {
+            value
+        }
+    }
+}
+
+impl<T: ClampPolicy> ClampedValue<T> {
     /// Creates a new `ClampedValue<T>`.
     ///
     /// # Panics
@@ -21,13 +138,21 @@ impl<T: PartialOrd + Clone> ClampedValue<T> {
     /// - `min` is larger than `max`
     /// - `value` is not within `min` and `max`
     pub fn new(min: T, value: T, max: T) -> Self {
+        Self::try_new(min, value, max).unwrap()
+    }
+
+    /// Creates a new `ClampedValue<T>`, returning a [`ClampedError`] instead of panicking if
+    /// either:
+    /// - `min` is larger than `max`
+    /// - `value` is not within `min` and `max` (a NaN `value` counts as out of bounds)
+    pub fn try_new(min: T, value: T, max: T) -> Result<Self, ClampedError> {
         if min > max {
-            panic!("Cannot create a clamped value where the minimum is larger than the maximum");
-        } else if value < min || value > max {
-            panic!("Cannot create a clamped value where the value is not within the minimum and maximum");
+            Err(ClampedError::MinGreaterThanMax)
+        } else if value.is_out_of_bounds(&min, &max) {
+            Err(ClampedError::ValueOutOfBounds)
+        } else {
+            Ok(Self { value, min, max })
         }
-
-        Self { value, min, max }
     }
 
     pub fn value(&self) -> &T {
@@ -50,13 +175,22 @@ impl<T: PartialOrd + Clone> ClampedValue<T> {
     /// - `new_min` is larger than the maximum
     /// - `new_min` is larger than the current value
     pub fn set_min(&mut self, new_min: T) {
+        self.try_set_min(new_min).unwrap()
+    }
+
+    /// Sets the minimum to `new_min`, returning a [`ClampedError`] instead of panicking if
+    /// either:
+    /// - `new_min` is larger than the maximum
+    /// - `new_min` is larger than the current value
+    pub fn try_set_min(&mut self, new_min: T) -> Result<(), ClampedError> {
         if new_min > self.max {
-            panic!("Cannot set the minimum to a value that is larger than the maximum");
+            return Err(ClampedError::MinGreaterThanMax);
         } else if new_min > self.value {
-            panic!("Cannot set the minimum to a value that is larger than the current value");
+            return Err(ClampedError::MinAboveValue);
         }
 
         self.min = new_min;
+        Ok(())
     }
 
     /// Sets the maximum to `new_max`.
@@ -67,13 +201,22 @@ impl<T: PartialOrd + Clone> ClampedValue<T> {
     /// - `new_max` is smaller than the minimum
     /// - `new_max` is smaller than the current value
     pub fn set_max(&mut self, new_max: T) {
+        self.try_set_max(new_max).unwrap()
+    }
+
+    /// Sets the maximum to `new_max`, returning a [`ClampedError`] instead of panicking if
+    /// either:
+    /// - `new_max` is smaller than the minimum
+    /// - `new_max` is smaller than the current value
+    pub fn try_set_max(&mut self, new_max: T) -> Result<(), ClampedError> {
         if new_max < self.min {
-            panic!("Cannot set the maximum to a value that is smaller than the minimum");
+            return Err(ClampedError::MinGreaterThanMax);
         } else if new_max < self.value {
-            panic!("Cannot set the maximum to a value that is smaller than the current value")
+            return Err(ClampedError::MaxBelowValue);
         }
 
         self.max = new_max;
+        Ok(())
     }
 
     /// Sets the value to `new_value`, saturating at min or max if `new_value` is outside those bounds.
@@ -84,17 +227,66 @@ impl<T: PartialOrd + Clone> ClampedValue<T> {
 
     // clamps self.value in between self.min and self.max
     fn clamp(&mut self) {
-        if self.value < self.min {
-            self.value = self.min.clone();
-        } else if self.value > self.max {
-            self.value = self.max.clone();
-        }
+        self.value = T::resolve(self.value.clone(), &self.min, &self.max);
+    }
+}
+
+impl<T: Bounded + ClampPolicy> ClampedValue<T> {
+    /// Creates a new `ClampedValue<T>` spanning the full range of `T`, using
+    /// [`Bounded::min_value`] and [`Bounded::max_value`] as the minimum and maximum.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use clamped_values::ClampedValue;
+    ///
+    /// let clamped_value = ClampedValue::<u8>::bounded(200);
+    ///
+    /// assert_eq!(*clamped_value.min(), 0);
+    /// assert_eq!(*clamped_value.max(), 255);
+    /// ```
+    pub fn bounded(value: T) -> Self {
+        Self::new(T::min_value(), value, T::max_value())
+    }
+
+    /// Creates a new `ClampedValue<T>` with `min` as the minimum and [`Bounded::max_value`] as
+    /// the maximum.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use clamped_values::ClampedValue;
+    ///
+    /// let clamped_value = ClampedValue::<u8>::with_min(10, 200);
+    ///
+    /// assert_eq!(*clamped_value.min(), 10);
+    /// assert_eq!(*clamped_value.max(), 255);
+    /// ```
+    pub fn with_min(min: T, value: T) -> Self {
+        Self::new(min, value, T::max_value())
+    }
+
+    /// Creates a new `ClampedValue<T>` with [`Bounded::min_value`] as the minimum and `max` as
+    /// the maximum.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use clamped_values::ClampedValue;
+    ///
+    /// let clamped_value = ClampedValue::<u8>::with_max(200, 220);
+    ///
+    /// assert_eq!(*clamped_value.min(), 0);
+    /// assert_eq!(*clamped_value.max(), 220);
+    /// ```
+    pub fn with_max(value: T, max: T) -> Self {
+        Self::new(T::min_value(), value, max)
     }
 }
 
 impl<T> ClampedValue<T>
 where
-    T: Into<f32> + Sub<Output = T> + PartialOrd + Clone,
+    T: Into<f32> + Sub<Output = T> + ClampPolicy,
 {
     /// Returns an f32 ranging from 0.0 to 1.0, representing the current value
     /// in relation to the minimum and maximum, where 0.0 is the minimum and
@@ -116,7 +308,31 @@ where
 
 impl<T> ClampedValue<T>
 where
-    T: Into<f64> + Sub<Output = T> + PartialOrd + Clone,
+    T: Into<f32> + NumCast + Sub<Output = T> + ClampPolicy,
+{
+    /// Sets the value to the point `t` of the way between the minimum and maximum, where `t` is
+    /// a fraction in `[0.0, 1.0]` (clamped first if it falls outside that range). This is the
+    /// inverse of [`ClampedValue::percent_f32`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use clamped_values::ClampedValue;
+    ///
+    /// let mut clamped_value = ClampedValue::<u8>::new(50, 50, 100);
+    ///
+    /// clamped_value.set_percent_f32(0.5);
+    ///
+    /// assert_eq!(*clamped_value.value(), 75);
+    /// ```
+    pub fn set_percent_f32(&mut self, t: f32) {
+        self.set_percent(t)
+    }
+}
+
+impl<T> ClampedValue<T>
+where
+    T: Into<f64> + Sub<Output = T> + ClampPolicy,
 {
     /// Returns an f64 ranging from 0.0 to 1.0, representing the current value
     /// in relation to the minimum and maximum, where 0.0 is the minimum and
@@ -136,25 +352,138 @@ where
     }
 }
 
+impl<T> ClampedValue<T>
+where
+    T: Into<f64> + NumCast + Sub<Output = T> + ClampPolicy,
+{
+    /// Sets the value to the point `t` of the way between the minimum and maximum, where `t` is
+    /// a fraction in `[0.0, 1.0]` (clamped first if it falls outside that range). This is the
+    /// inverse of [`ClampedValue::percent_f64`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use clamped_values::ClampedValue;
+    ///
+    /// let mut clamped_value = ClampedValue::<u8>::new(50, 50, 100);
+    ///
+    /// clamped_value.set_percent_f64(0.5);
+    ///
+    /// assert_eq!(*clamped_value.value(), 75);
+    /// ```
+    pub fn set_percent_f64(&mut self, t: f64) {
+        self.set_percent(t)
+    }
+}
+
 // generic version of the percent code so that we can use the same logic for f32 and f64
-impl<T: Sub<Output = T> + PartialOrd + Clone> ClampedValue<T> {
+impl<T: Sub<Output = T> + ClampPolicy> ClampedValue<T> {
     fn percent<U>(&self) -> U
     where
-        U: Div<Output = U>,
+        U: ClampedFloat,
         T: Into<U>,
     {
+        // a degenerate range (min == max) has no meaningful fraction; treat the value as
+        // sitting at the start of the range rather than computing a NaN-producing 0.0 / 0.0
+        if self.min == self.max {
+            return U::zero();
+        }
+
         // we can sub these values by self.min without worrying about overflow due to the fact that
         // self.min is ALWAYS smaller than or equal to self.value and self.max
         (self.value.clone() - self.min.clone()).into()
             / (self.max.clone() - self.min.clone()).into()
     }
+
+    /// Projects the current value from `[min, max]` onto an arbitrary output range
+    /// `[out_min, out_max]`. This is the generalization of [`ClampedValue::percent_f32`] /
+    /// [`ClampedValue::percent_f64`], which is the special case of `out_min = 0.0` and
+    /// `out_max = 1.0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use clamped_values::ClampedValue;
+    ///
+    /// // a health value of 75 out of 0..=100, drawn as a 200px-wide bar
+    /// let health = ClampedValue::<u8>::new(0, 75, 100);
+    ///
+    /// assert_eq!(health.map_to(0.0, 200.0), 150.0);
+    /// ```
+    pub fn map_to<U>(&self, out_min: U, out_max: U) -> U
+    where
+        U: ClampedFloat,
+        T: Into<U>,
+    {
+        let percent = self.percent::<U>();
+        out_min + percent * (out_max - out_min)
+    }
+
+    // generic version of set_percent so that we can use the same logic for f32 and f64
+    fn set_percent<U>(&mut self, t: U)
+    where
+        U: ClampedFloat,
+        T: Into<U> + NumCast,
+    {
+        let t = if t < U::zero() {
+            U::zero()
+        } else if t > U::one() {
+            U::one()
+        } else {
+            t
+        };
+
+        let min: U = self.min.clone().into();
+        let max: U = self.max.clone().into();
+
+        self.value = T::from((min + t * (max - min)).round()).unwrap();
+        self.clamp();
+    }
+}
+
+impl<T> ClampedValue<T>
+where
+    T: Into<f64> + NumCast + Sub<Output = T> + ClampPolicy,
+{
+    /// Resizes the clamp window to `[new_min, new_max]`, preserving the value's relative
+    /// position within the window (the same fraction of the way between minimum and maximum)
+    /// rather than merely re-clamping it to the new bounds.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `new_min` is larger than `new_max`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use clamped_values::ClampedValue;
+    ///
+    /// // a sensor reading of 50, halfway through its 0..=100 range
+    /// let mut reading = ClampedValue::<u8>::new(0, 50, 100);
+    ///
+    /// // rescale onto a 0..=10 range, keeping it at the halfway point
+    /// reading.remap(0, 10);
+    ///
+    /// assert_eq!(*reading.value(), 5);
+    /// ```
+    pub fn remap(&mut self, new_min: T, new_max: T) {
+        if new_min > new_max {
+            panic!("Cannot remap to a minimum that is larger than the maximum");
+        }
+
+        let fraction = self.percent::<f64>();
+
+        self.min = new_min;
+        self.max = new_max;
+        self.set_percent(fraction);
+    }
 }
 
 // For the following three impl blocks, the "Saturating" version of the operation is implemented as opposed to
 // the regular operation due to the fact that the regular operations allow the possibility of
 // overflowing (in debug) or wrapping (in release), which is unexpected behaviour.
 
-impl<T: SaturatingAdd + PartialOrd + Clone> AddAssign<T> for ClampedValue<T> {
+impl<T: SaturatingAdd + ClampPolicy> AddAssign<T> for ClampedValue<T> {
     /// Adds `rhs` to the current value, saturating at the minimum or maximum.
     ///
     /// # Examples
@@ -174,7 +503,7 @@ impl<T: SaturatingAdd + PartialOrd + Clone> AddAssign<T> for ClampedValue<T> {
     }
 }
 
-impl<T: SaturatingSub + PartialOrd + Clone> SubAssign<T> for ClampedValue<T> {
+impl<T: SaturatingSub + ClampPolicy> SubAssign<T> for ClampedValue<T> {
     /// Subtracts `rhs` from the value, saturating at the minimum or maximum.
     /// 
     /// # Examples
@@ -194,7 +523,7 @@ impl<T: SaturatingSub + PartialOrd + Clone> SubAssign<T> for ClampedValue<T> {
     }
 }
 
-impl<T: SaturatingMul + PartialOrd + Clone> MulAssign<T> for ClampedValue<T> {
+impl<T: SaturatingMul + ClampPolicy> MulAssign<T> for ClampedValue<T> {
     /// Multiplies the value by `rhs`, saturating at the minimum or maximum.
     /// 
     /// # Examples
@@ -214,29 +543,40 @@ impl<T: SaturatingMul + PartialOrd + Clone> MulAssign<T> for ClampedValue<T> {
     }
 }
 
-impl<T: Div<Output = T> + PartialOrd + Clone> DivAssign<T> for ClampedValue<T> {
-    /// Divides the value by `rhs`, saturating at the minimum or maximum.
-    /// 
+impl<T: CheckedDiv + Zero + ClampPolicy> DivAssign<T> for ClampedValue<T> {
+    /// Divides the value by `rhs`, saturating at the minimum or maximum if the division
+    /// overflows or `rhs` is zero, rather than panicking or wrapping.
+    ///
     /// # Examples
-    /// 
+    ///
     /// ```
     /// use clamped_values::ClampedValue;
-    /// 
+    ///
     /// let mut clamped_value = ClampedValue::new(0, 8, 10);
-    /// 
+    ///
     /// clamped_value /= 2;
-    /// 
+    ///
     /// assert_eq!(*clamped_value.value(), 4);
-    /// ``` 
+    ///
+    /// clamped_value /= 0;
+    ///
+    /// assert_eq!(*clamped_value.value(), *clamped_value.max());
+    /// ```
     fn div_assign(&mut self, rhs: T) {
-        self.value = self.value.clone() / rhs;
+        self.value = match self.value.checked_div(&rhs) {
+            Some(result) => result,
+            // division by zero and overflowing division (e.g. i32::MIN / -1) both land here;
+            // in either case the mathematical result's sign tells us which bound to saturate to
+            None if (self.value < T::zero()) == (rhs < T::zero()) => self.max.clone(),
+            None => self.min.clone(),
+        };
         self.clamp();
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::ClampedValue;
+    use crate::{ClampedError, ClampedValue};
 
     #[test]
     fn new() {
@@ -299,6 +639,63 @@ mod tests {
         ClampedValue::new(10, 20, 30).set_max(15);
     }
 
+    #[test]
+    fn try_new() {
+        assert!(ClampedValue::try_new(10, 20, 30).is_ok());
+        assert_eq!(
+            ClampedValue::try_new(30, 10, 20).unwrap_err(),
+            ClampedError::MinGreaterThanMax
+        );
+        assert_eq!(
+            ClampedValue::try_new(10, 40, 30).unwrap_err(),
+            ClampedError::ValueOutOfBounds
+        );
+    }
+
+    #[test]
+    fn try_set() {
+        let mut clamped_value = ClampedValue::new(10, 20, 30);
+
+        assert_eq!(
+            clamped_value.try_set_min(40).unwrap_err(),
+            ClampedError::MinGreaterThanMax
+        );
+        assert_eq!(
+            clamped_value.try_set_min(25).unwrap_err(),
+            ClampedError::MinAboveValue
+        );
+        assert_eq!(
+            clamped_value.try_set_max(0).unwrap_err(),
+            ClampedError::MinGreaterThanMax
+        );
+        assert_eq!(
+            clamped_value.try_set_max(15).unwrap_err(),
+            ClampedError::MaxBelowValue
+        );
+
+        assert!(clamped_value.try_set_min(15).is_ok());
+        assert_eq!(*clamped_value.min(), 15);
+
+        assert!(clamped_value.try_set_max(25).is_ok());
+        assert_eq!(*clamped_value.max(), 25);
+    }
+
+    #[test]
+    fn bounded() {
+        let clamped_value = ClampedValue::<u8>::bounded(200);
+        assert_eq!(*clamped_value.min(), u8::MIN);
+        assert_eq!(*clamped_value.max(), u8::MAX);
+        assert_eq!(*clamped_value.value(), 200);
+
+        let clamped_value = ClampedValue::<u8>::with_min(10, 200);
+        assert_eq!(*clamped_value.min(), 10);
+        assert_eq!(*clamped_value.max(), u8::MAX);
+
+        let clamped_value = ClampedValue::<u8>::with_max(200, 220);
+        assert_eq!(*clamped_value.min(), u8::MIN);
+        assert_eq!(*clamped_value.max(), 220);
+    }
+
     #[test]
     fn percent() {
         // works with all positive numbers
@@ -315,6 +712,87 @@ mod tests {
         let c = ClampedValue::<i8>::new(-40, -10, 40);
         assert_eq!(c.percent_f32(), 0.375);
         assert_eq!(c.percent_f64(), 0.375);
+
+        // a degenerate range (min == max) would otherwise divide by zero
+        let c = ClampedValue::<u8>::new(5, 5, 5);
+        assert_eq!(c.percent_f32(), 0.0);
+        assert_eq!(c.percent_f64(), 0.0);
+    }
+
+    #[test]
+    fn set_percent() {
+        let mut c = ClampedValue::<u8>::new(75, 100, 125);
+        c.set_percent_f32(0.0);
+        assert_eq!(*c.value(), 75);
+        c.set_percent_f64(1.0);
+        assert_eq!(*c.value(), 125);
+        c.set_percent_f32(0.5);
+        assert_eq!(*c.value(), 100);
+
+        // out of range fractions are clamped to [0.0, 1.0] first
+        c.set_percent_f32(-10.0);
+        assert_eq!(*c.value(), 75);
+        c.set_percent_f64(10.0);
+        assert_eq!(*c.value(), 125);
+    }
+
+    #[test]
+    fn nan_is_never_stored() {
+        assert_eq!(
+            ClampedValue::try_new(0.0, f32::NAN, 10.0).unwrap_err(),
+            ClampedError::ValueOutOfBounds
+        );
+        assert_eq!(
+            ClampedValue::try_new(0.0, f64::NAN, 10.0).unwrap_err(),
+            ClampedError::ValueOutOfBounds
+        );
+
+        let mut c = ClampedValue::new(0.0_f32, 5.0, 10.0);
+        c.set(f32::NAN);
+        assert_eq!(*c.value(), *c.min());
+
+        let mut c = ClampedValue::new(0.0_f64, 5.0, 10.0);
+        c.set(f64::NAN);
+        assert_eq!(*c.value(), *c.min());
+    }
+
+    #[test]
+    fn map_to() {
+        let c = ClampedValue::<u8>::new(0, 75, 100);
+        assert_eq!(c.map_to(0.0, 200.0), 150.0);
+        assert_eq!(c.map_to(-1.0, 1.0), 0.5);
+
+        // a degenerate range (min == max) would otherwise leak a NaN
+        let c = ClampedValue::<u8>::new(5, 5, 5);
+        assert_eq!(c.map_to(0.0, 200.0), 0.0);
+    }
+
+    #[test]
+    fn remap() {
+        let mut c = ClampedValue::<u8>::new(0, 50, 100);
+        c.remap(0, 10);
+        assert_eq!(*c.min(), 0);
+        assert_eq!(*c.max(), 10);
+        assert_eq!(*c.value(), 5);
+
+        c.remap(50, 150);
+        assert_eq!(*c.value(), 100);
+    }
+
+    #[test]
+    fn remap_degenerate_source_range() {
+        // a degenerate source range (min == max) would otherwise panic trying to unwrap a NaN
+        let mut c = ClampedValue::<u8>::new(5, 5, 5);
+        c.remap(0, 10);
+        assert_eq!(*c.min(), 0);
+        assert_eq!(*c.max(), 10);
+        assert_eq!(*c.value(), 0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn remap_min_larger_than_max() {
+        ClampedValue::<u8>::new(0, 50, 100).remap(10, 5);
     }
 
     #[test]
@@ -333,4 +811,22 @@ mod tests {
         clamped_value /= 10;
         assert_eq!(*clamped_value.value(), *clamped_value.min());
     }
+
+    #[test]
+    fn div_assign_by_zero() {
+        let mut positive = ClampedValue::new(0, 8, 10);
+        positive /= 0;
+        assert_eq!(*positive.value(), *positive.max());
+
+        let mut negative = ClampedValue::new(-10, -8, 0);
+        negative /= 0;
+        assert_eq!(*negative.value(), *negative.min());
+    }
+
+    #[test]
+    fn div_assign_overflow() {
+        let mut clamped_value = ClampedValue::<i32>::bounded(i32::MIN);
+        clamped_value /= -1;
+        assert_eq!(*clamped_value.value(), i32::MAX);
+    }
 }